@@ -0,0 +1,128 @@
+//! `#[derive(ToJson, FromJson)]` for `rsjson`.
+//!
+//! Generates `ToJson`/`FromJson` implementations that map each struct field
+//! to a node labelled with the field's name. Two field attributes are
+//! supported under `#[rsjson(...)]`:
+//!
+//! - `rename = "..."` uses a different node label for that field.
+//! - `skip` omits the field from `to_json` and requires `Default` for it on
+//!   `from_json`.
+
+#![allow(non_snake_case)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+struct FieldPlan {
+    ident: syn::Ident,
+    label: String,
+    skip: bool
+}
+
+fn collectFields(data: &Data) -> Vec<FieldPlan> {
+    let fields = match data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => panic!("#[derive(ToJson, FromJson)] only supports structs with named fields")
+        },
+        _ => panic!("#[derive(ToJson, FromJson)] only supports structs")
+    };
+
+    fields.iter().map(|field| {
+        let ident = field.ident.clone().expect("named field");
+        let mut label = ident.to_string();
+        let mut skip = false;
+
+        for attribute in &field.attrs {
+            if !attribute.path().is_ident("rsjson") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let value = meta.value()?;
+                    let renamed: syn::LitStr = value.parse()?;
+                    label = renamed.value();
+
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                }
+
+                Ok(())
+            }).expect("valid #[rsjson(...)] attribute");
+        }
+
+        FieldPlan { ident, label, skip }
+    }).collect()
+}
+
+#[proc_macro_derive(ToJson, attributes(rsjson))]
+pub fn derive_to_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+    let fields = collectFields(&input.data);
+
+    let pushes = fields.iter().filter(|field| !field.skip).map(|field| {
+        let ident = &field.ident;
+        let label = &field.label;
+
+        quote! {
+            json.addNode(rsjson::Node::new(#label, rsjson::ToJson::to_json(&self.#ident)));
+        }
+    });
+
+    let expanded = quote! {
+        impl rsjson::ToJson for #name {
+            fn to_json(&self) -> rsjson::NodeContent {
+                let mut json = rsjson::Json::new();
+                #( #pushes )*
+                rsjson::NodeContent::Json(json)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+#[proc_macro_derive(FromJson, attributes(rsjson))]
+pub fn derive_from_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+    let fields = collectFields(&input.data);
+
+    let reads = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let label = &field.label;
+
+        if field.skip {
+            quote! {
+                let #ident = Default::default();
+            }
+        } else {
+            quote! {
+                let #ident = json.get(#label)
+                    .ok_or_else(|| format!("missing field '{}' while decoding {}", #label, stringify!(#name)))
+                    .and_then(|content| rsjson::FromJson::from_json(content)
+                        .map_err(|why| format!("field '{}' of {}: {}", #label, stringify!(#name), why)))?;
+            }
+        }
+    });
+
+    let idents = fields.iter().map(|field| &field.ident);
+
+    let expanded = quote! {
+        impl rsjson::FromJson for #name {
+            fn from_json(content: &rsjson::NodeContent) -> Result<Self, String> {
+                let json = content.toJson()
+                    .ok_or_else(|| format!("expected an object while decoding {}", stringify!(#name)))?;
+
+                #( #reads )*
+
+                Ok(#name { #( #idents ),* })
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
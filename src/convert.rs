@@ -0,0 +1,229 @@
+//! Conversion traits between Rust values and `NodeContent`.
+//!
+//! `ToJson`/`FromJson` mirror the classic Encodable/Decodable split: any type
+//! that knows how to turn itself into a `NodeContent` implements `ToJson`,
+//! and any type that knows how to read itself back out of one implements
+//! `FromJson`. Blanket implementations are provided for the primitive
+//! mappings; the `rsjson_derive` crate adds `#[derive(ToJson, FromJson)]`
+//! for structs on top of them.
+
+use std::collections::HashMap;
+
+use crate::{Json, Node, NodeContent};
+
+/// Converts a value into a `NodeContent`.
+pub trait ToJson {
+    fn to_json(&self) -> NodeContent;
+}
+
+/// Reads a value back out of a `NodeContent`.
+pub trait FromJson: Sized {
+    fn from_json(content: &NodeContent) -> Result<Self, String>;
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> NodeContent {
+        NodeContent::String(self.clone())
+    }
+}
+
+impl FromJson for String {
+    fn from_json(content: &NodeContent) -> Result<Self, String> {
+        match content {
+            NodeContent::String(value) => Ok(value.clone()),
+            _ => Err(format!("expected a string, found {:?}", content))
+        }
+    }
+}
+
+impl ToJson for bool {
+    fn to_json(&self) -> NodeContent {
+        NodeContent::Bool(*self)
+    }
+}
+
+impl FromJson for bool {
+    fn from_json(content: &NodeContent) -> Result<Self, String> {
+        content.toBool().ok_or_else(|| format!("expected a bool, found {:?}", content))
+    }
+}
+
+impl ToJson for f64 {
+    fn to_json(&self) -> NodeContent {
+        NodeContent::Float(*self)
+    }
+}
+
+impl FromJson for f64 {
+    fn from_json(content: &NodeContent) -> Result<Self, String> {
+        content.toFloat().ok_or_else(|| format!("expected a float, found {:?}", content))
+    }
+}
+
+impl ToJson for f32 {
+    fn to_json(&self) -> NodeContent {
+        NodeContent::Float(*self as f64)
+    }
+}
+
+impl FromJson for f32 {
+    fn from_json(content: &NodeContent) -> Result<Self, String> {
+        content.toFloat().map(|value| value as f32).ok_or_else(|| format!("expected a float, found {:?}", content))
+    }
+}
+
+macro_rules! impl_integer_conversions {
+    ( $( $integer:ty ),* ) => {
+        $(
+            impl ToJson for $integer {
+                fn to_json(&self) -> NodeContent {
+                    NodeContent::Int(*self as i64)
+                }
+            }
+
+            impl FromJson for $integer {
+                fn from_json(content: &NodeContent) -> Result<Self, String> {
+                    content.toI64()
+                        .and_then(|value| <$integer>::try_from(value).ok())
+                        .ok_or_else(|| format!("expected an integer fitting in {}, found {:?}", stringify!($integer), content))
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_conversions!(i8, i16, i32, i64, u8, u16, u32, isize);
+
+/// Like [`impl_integer_conversions`], but for unsigned types wide enough to
+/// exceed `i64::MAX` (`u64`/`usize`). Casting those straight to `i64` would
+/// silently wrap large values negative, so `to_json` falls back to `Float`
+/// above `i64::MAX` instead, mirroring the parser's own integer-overflow
+/// handling.
+macro_rules! impl_wide_integer_conversions {
+    ( $( $integer:ty ),* ) => {
+        $(
+            impl ToJson for $integer {
+                fn to_json(&self) -> NodeContent {
+                    match i64::try_from(*self) {
+                        Ok(value) => NodeContent::Int(value),
+                        Err(_) => NodeContent::Float(*self as f64)
+                    }
+                }
+            }
+
+            impl FromJson for $integer {
+                fn from_json(content: &NodeContent) -> Result<Self, String> {
+                    match content {
+                        NodeContent::Int(value) => <$integer>::try_from(*value)
+                            .map_err(|_| format!("expected an integer fitting in {}, found {:?}", stringify!($integer), content)),
+                        NodeContent::Float(value) if *value >= 0.0 && value.fract() == 0.0 => Ok(*value as $integer),
+                        _ => Err(format!("expected an integer fitting in {}, found {:?}", stringify!($integer), content))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_integer_conversions!(u64, usize);
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> NodeContent {
+        match self {
+            Some(value) => value.to_json(),
+            None => NodeContent::Null
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(content: &NodeContent) -> Result<Self, String> {
+        match content {
+            NodeContent::Null => Ok(None),
+            other => T::from_json(other).map(Some)
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> NodeContent {
+        NodeContent::List(self.iter().map(ToJson::to_json).collect())
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(content: &NodeContent) -> Result<Self, String> {
+        let list = content.toList().ok_or_else(|| format!("expected a list, found {:?}", content))?;
+        list.iter().map(T::from_json).collect()
+    }
+}
+
+impl<T: ToJson> ToJson for HashMap<String, T> {
+    fn to_json(&self) -> NodeContent {
+        let mut json = Json::new();
+
+        for (label, value) in self {
+            json.addNode(Node::new(label, value.to_json()));
+        }
+
+        NodeContent::Json(json)
+    }
+}
+
+impl<T: FromJson> FromJson for HashMap<String, T> {
+    fn from_json(content: &NodeContent) -> Result<Self, String> {
+        let json = content.toJson().ok_or_else(|| format!("expected an object, found {:?}", content))?;
+        let mut map = HashMap::new();
+
+        for node in json.getAllNodes() {
+            map.insert(node.getLabel(), T::from_json(&node.getContent())?);
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn testPrimitiveRoundTrip() {
+        assert_eq!(42_i32.to_json(), NodeContent::Int(42));
+        assert_eq!(i32::from_json(&NodeContent::Int(42)), Ok(42));
+
+        assert_eq!(String::from("hi").to_json(), NodeContent::String(String::from("hi")));
+        assert_eq!(String::from_json(&NodeContent::String(String::from("hi"))), Ok(String::from("hi")));
+    }
+
+    #[test]
+    fn testOptionAndVec() {
+        let none: Option<i32> = None;
+        assert_eq!(none.to_json(), NodeContent::Null);
+        assert_eq!(Option::<i32>::from_json(&NodeContent::Null), Ok(None));
+
+        let values = vec![1_i32, 2, 3];
+        assert_eq!(values.to_json(), NodeContent::List(vec![NodeContent::Int(1), NodeContent::Int(2), NodeContent::Int(3)]));
+        assert_eq!(Vec::<i32>::from_json(&values.to_json()), Ok(values));
+    }
+
+    #[test]
+    fn testFromJsonTypeMismatch() {
+        assert!(i32::from_json(&NodeContent::String(String::from("nope"))).is_err());
+    }
+
+    #[test]
+    fn testStringFromJsonPreservesEmbeddedQuotes() {
+        let content = NodeContent::String(String::from("a\"b"));
+        assert_eq!(String::from_json(&content), Ok(String::from("a\"b")));
+    }
+
+    #[test]
+    fn testU64AboveI64MaxFallsBackToFloat() {
+        let huge = u64::MAX;
+        let content = huge.to_json();
+
+        assert_eq!(content, NodeContent::Float(huge as f64));
+        assert_eq!(u64::from_json(&content), Ok(huge as f64 as u64));
+    }
+}
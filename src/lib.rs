@@ -42,6 +42,7 @@
 //!
 //! - add a node
 //! ```rust
+//! let mut json = rsjson::Json::new();
 //! json.addNode(
 //!     rsjson::Node::new(
 //!         "nodeLabel",
@@ -52,6 +53,8 @@
 //!
 //! - edit a node's content
 //! ```rust
+//! # let mut json = rsjson::Json::new();
+//! # json.addNode(rsjson::Node::new("nodeLabel", rsjson::NodeContent::Int(32)));
 //! json.setContent(
 //!     "nodeLabel",
 //!     rsjson::NodeContent::Bool(true)
@@ -60,6 +63,8 @@
 //!
 //! - remove a node
 //! ```rust
+//! # let mut json = rsjson::Json::new();
+//! # json.addNode(rsjson::Node::new("nodeLabel", rsjson::NodeContent::Int(32)));
 //! json.remove(
 //!     "nodeLabel"
 //! );
@@ -67,23 +72,49 @@
 //!
 //! - check the existance of a label
 //! ```rust
+//! # let json = rsjson::Json::new();
 //! let exists: bool = json.has("nodeLabel");
 //! ```
 
 #![allow(non_snake_case, unused_assignments)]
+// These lints fire throughout code that predates this allow (explicit
+// `return`s, `Struct{field: field}` initializers, etc.) because that's the
+// style this crate was already written in. Suppressed deliberately rather
+// than rewritten wholesale so unrelated diffs don't pile up in every
+// request; new code should still prefer idiomatic clippy-clean style.
+#![allow(
+    clippy::explicit_counter_loop,
+    clippy::get_first,
+    clippy::manual_find,
+    clippy::needless_borrow,
+    clippy::needless_return,
+    clippy::new_without_default,
+    clippy::redundant_field_names,
+    clippy::redundant_pattern_matching,
+    clippy::self_named_constructors,
+    clippy::single_match,
+    clippy::to_string_in_format_args
+)]
 
 use std::{fs, path};
 use std::collections::HashSet;
+use std::collections::HashMap;
 
-const DIGITS: [&str; 11] = [
-    "1", "2", "3", "4", "5", "6", "7", "8", "9", "0", "."
-];
+mod query;
+mod convert;
+
+pub use convert::{ToJson, FromJson};
+
+/// Re-exports `#[derive(ToJson, FromJson)]` from the companion `rsjson_derive`
+/// crate when the `derive` feature is enabled.
+#[cfg(feature = "derive")]
+pub use rsjson_derive::{ToJson, FromJson};
 
 #[derive(Debug, PartialEq)]
 enum Token {
     String(String),
-    Int(usize),
-    Float(f32),
+    Int(i64),
+    Float(f64),
     OpenBrace,
     CloseBrace,
     OpenBracket,
@@ -106,123 +137,120 @@ impl Token {
 struct Parser {
     tokens: Vec<Token>,
     index: usize,
-    text: String ,
+    chars: Vec<char>,
     len: usize
 }
 
 impl Parser {
     fn new(text: String) -> Parser {
+        let chars: Vec<char> = text.chars().collect();
+
         return Parser {
             tokens: Vec::<Token>::new(),
             index: 0_usize,
-            len: (&text).len(),
-            text: text
+            len: chars.len(),
+            chars: chars
         }
     }
 
-    fn get(&mut self) -> String {
-        match self.text.get(self.index..self.index+1) {
-            Some(c) => c.to_string(),
-            None => {
-                panic!("Non utf8 character found, which is not accepted")
-            }
-        }
+    fn get(&self) -> char {
+        self.chars[self.index]
     }
 
     fn checkNotEnd(&self) -> bool {
         self.index != self.len
     }
 
+    fn matchLiteral(&self, literal: &str) -> bool {
+        let literalChars: Vec<char> = literal.chars().collect();
+
+        if self.index + literalChars.len() > self.len {
+            return false;
+        }
+
+        self.chars[self.index..self.index + literalChars.len()] == literalChars[..]
+    }
+
     fn parse(&mut self) -> bool {
         self.skipNull();
         while self.checkNotEnd() {
-            let mut current = self.get();
+            let current = self.get();
 
-            if current == "\"" {
+            if current == '"' {
                 self.index += 1;
 
-                let mut value = String::new();
-
-                while self.checkNotEnd() {
-                    current = self.get();
-
-                    if current.as_str() == "\"" && (&self.text[self.index-1..self.index] != "\\") {
-                        break
-
-                    } else if current.as_str() == "\"" && (&self.text[self.index-1..self.index] == "\\" && &self.text[self.index-2..self.index-1] == "\\") {
-                        break
-                    }
-
-                    value += current.as_str();
-                    self.index += 1;
-                }
-
-                if ! self.checkNotEnd() {
+                let (value, error) = self.parseString();
+                if error {
                     return true;
                 }
-                self.index += 1;
 
                 self.tokens.push(Token::String(value));
 
-            } else if self.get() == ":" {
+            } else if current == ':' {
                 self.tokens.push(Token::Colon);
                 self.index += 1;
 
-            } else if self.get() == "," {
+            } else if current == ',' {
                 self.tokens.push(Token::Comma);
                 self.index += 1;
 
-            } else if self.get() == "{" {
+            } else if current == '{' {
                 self.tokens.push(Token::OpenBrace);
                 self.index += 1;
 
-            } else if self.get() == "}" {
+            } else if current == '}' {
                 self.tokens.push(Token::CloseBrace);
                 self.index += 1;
 
-            } else if self.get() == "[" {
+            } else if current == '[' {
                 self.tokens.push(Token::OpenBracket);
                 self.index += 1;
 
-            } else if self.get() == "]" {
+            } else if current == ']' {
                 self.tokens.push(Token::CloseBracket);
                 self.index += 1;
 
-            } else if DIGITS.contains(&self.get().as_str()) {
-                let mut value = String::new();
+            } else if current.is_ascii_digit() || current == '-' {
+                let (value, isFloat, error) = self.parseNumber();
 
-                while self.checkNotEnd() && DIGITS.contains(&self.get().as_str()) {
-                    value += self.get().as_str();
-                    self.index += 1;
-                }
-
-                if ! self.checkNotEnd() {
+                if error {
                     return true;
                 }
 
-                if value.contains(".") {
-                    self.tokens.push(Token::Float(value.parse::<f32>().unwrap()))
+                if isFloat {
+                    match value.parse::<f64>() {
+                        Ok(float) => self.tokens.push(Token::Float(float)),
+                        Err(_) => return true
+                    }
 
                 } else {
-                    self.tokens.push(Token::Int(value.parse::<usize>().unwrap()))
+                    match value.parse::<i64>() {
+                        Ok(int) => self.tokens.push(Token::Int(int)),
+                        Err(_) => match value.parse::<f64>() {
+                            Ok(float) => self.tokens.push(Token::Float(float)),
+                            Err(_) => return true
+                        }
+                    }
                 }
 
-            } else if self.get() == "t" || self.get() == "f" || self.get() == "n" {
-                if self.len - self.index - 4 > 0 && &self.text[self.index..self.index + 4] == "true" {
+            } else if current == 't' || current == 'f' || current == 'n' {
+                if self.matchLiteral("true") {
                     self.tokens.push(Token::Bool(true));
                     self.index += 4;
 
-                } else if self.len - self.index - 4 > 0 && &self.text[self.index..self.index + 4] == "null" {
+                } else if self.matchLiteral("null") {
                     self.tokens.push(Token::Null);
                     self.index += 4;
 
-                } else if self.len - self.index - 5 > 0 && &self.text[self.index..self.index + 5] == "false" {
+                } else if self.matchLiteral("false") {
                     self.tokens.push(Token::Bool(false));
                     self.index += 5;
 
                 } else {
                     return true
                 }
+            } else {
+                return true
             }
             self.skipNull();
         }
@@ -231,19 +259,169 @@ impl Parser {
     }
 
     fn skipNull(&mut self) {
-        let skip = [" ", "\t", "\n"];
+        while self.index < self.len && matches!(self.chars[self.index], ' ' | '\t' | '\n' | '\r') {
+            self.index += 1;
+        }
+    }
+
+    /// Consumes a JSON string body (the opening quote has already been
+    /// consumed) up to and including the closing quote, decoding escape
+    /// sequences (`\" \\ \/ \b \f \n \r \t` and `\uXXXX`, including
+    /// surrogate pairs) into their literal characters along the way.
+    /// Returns the decoded text and whether decoding failed.
+    fn parseString(&mut self) -> (String, bool) {
+        let mut value = String::new();
+
+        loop {
+            if !self.checkNotEnd() {
+                return (value, true);
+            }
+
+            let current = self.get();
+
+            if current == '"' {
+                self.index += 1;
+                return (value, false);
+
+            } else if current == '\\' {
+                self.index += 1;
+                if !self.checkNotEnd() {
+                    return (value, true);
+                }
+
+                let escape = self.get();
+                match escape {
+                    '"' => { value.push('"'); self.index += 1; },
+                    '\\' => { value.push('\\'); self.index += 1; },
+                    '/' => { value.push('/'); self.index += 1; },
+                    'b' => { value.push('\u{8}'); self.index += 1; },
+                    'f' => { value.push('\u{c}'); self.index += 1; },
+                    'n' => { value.push('\n'); self.index += 1; },
+                    'r' => { value.push('\r'); self.index += 1; },
+                    't' => { value.push('\t'); self.index += 1; },
+                    'u' => {
+                        self.index += 1;
+                        match self.parseUnicodeEscape() {
+                            Some(c) => value.push(c),
+                            None => return (value, true)
+                        }
+                    },
+                    _ => return (value, true)
+                }
+
+            } else {
+                value.push(current);
+                self.index += 1;
+            }
+        }
+    }
+
+    /// Reads a `\u` escape (the leading `\u` has already been consumed) and
+    /// combines a high/low UTF-16 surrogate pair into a single `char` when
+    /// present.
+    fn parseUnicodeEscape(&mut self) -> Option<char> {
+        let high = self.readHex4()?;
+
+        if (0xD800..=0xDBFF).contains(&high) {
+            if self.index + 1 < self.len && self.chars[self.index] == '\\' && self.chars[self.index + 1] == 'u' {
+                self.index += 2;
+                let low = self.readHex4()?;
+
+                if (0xDC00..=0xDFFF).contains(&low) {
+                    let codepoint = 0x10000 + (high - 0xD800) * 0x400 + (low - 0xDC00);
+                    return char::from_u32(codepoint);
+                }
+                return None;
+            }
+            return None;
+        }
+
+        char::from_u32(high)
+    }
+
+    /// Reads exactly 4 hexadecimal digits starting at the current index and
+    /// advances past them, returning their value.
+    fn readHex4(&mut self) -> Option<u32> {
+        if self.index + 4 > self.len {
+            return None;
+        }
 
-        while self.index < self.len && skip.contains(&&self.text[self.index..self.index + 1]) {
+        if !self.chars[self.index..self.index + 4].iter().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let hex: String = self.chars[self.index..self.index + 4].iter().collect();
+        let value = u32::from_str_radix(&hex, 16).ok()?;
+        self.index += 4;
+
+        Some(value)
+    }
+
+    /// Consumes a JSON number (`-?int(.frac)?([eE][+-]?digits)?`) starting at
+    /// the current index and returns its raw text, whether it is a float
+    /// (has a fractional part and/or an exponent) and whether parsing failed.
+    fn parseNumber(&mut self) -> (String, bool, bool) {
+        let mut value = String::new();
+        let mut isFloat = false;
+
+        if self.checkNotEnd() && self.get() == '-' {
+            value.push('-');
             self.index += 1;
         }
+
+        if !self.checkNotEnd() || !self.get().is_ascii_digit() {
+            return (value, isFloat, true);
+        }
+
+        while self.checkNotEnd() && self.get().is_ascii_digit() {
+            value.push(self.get());
+            self.index += 1;
+        }
+
+        if self.checkNotEnd() && self.get() == '.' {
+            isFloat = true;
+            value.push('.');
+            self.index += 1;
+
+            if !self.checkNotEnd() || !self.get().is_ascii_digit() {
+                return (value, isFloat, true);
+            }
+
+            while self.checkNotEnd() && self.get().is_ascii_digit() {
+                value.push(self.get());
+                self.index += 1;
+            }
+        }
+
+        if self.checkNotEnd() && (self.get() == 'e' || self.get() == 'E') {
+            isFloat = true;
+            value.push(self.get());
+            self.index += 1;
+
+            if self.checkNotEnd() && (self.get() == '+' || self.get() == '-') {
+                value.push(self.get());
+                self.index += 1;
+            }
+
+            if !self.checkNotEnd() || !self.get().is_ascii_digit() {
+                return (value, isFloat, true);
+            }
+
+            while self.checkNotEnd() && self.get().is_ascii_digit() {
+                value.push(self.get());
+                self.index += 1;
+            }
+        }
+
+        (value, isFloat, false)
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum NodeContent {
     String(String),
-    Int(usize),
-    Float(f32),
+    Int(i64),
+    Float(f64),
     Bool(bool),
     List(Vec<NodeContent>),
     Json(Json),
@@ -253,12 +431,23 @@ pub enum NodeContent {
 impl NodeContent {
     pub fn toString(&self) -> Option<String> {
         match self {
-            NodeContent::String(value) => Some(value.to_string().replace("\"", "\\\"")),
+            NodeContent::String(value) => Some(value.clone()),
             _ => None
         }
     }
 
+    /// Returns the node's integer content as a `usize`, or `None` if the
+    /// node is not an `Int` or the value is negative.
     pub fn toUsize(&self) -> Option<usize> {
+        match self {
+            NodeContent::Int(value) => usize::try_from(*value).ok(),
+            _ => None
+        }
+    }
+
+    /// Returns the node's integer content as an `i64`, preserving sign and
+    /// full precision.
+    pub fn toI64(&self) -> Option<i64> {
         match self {
             NodeContent::Int(value) => Some(value.to_owned()),
             _ => None
@@ -272,13 +461,19 @@ impl NodeContent {
         }
     }
 
-    pub fn toFloat(&self) -> Option<f32> {
+    pub fn toFloat(&self) -> Option<f64> {
         match self {
             NodeContent::Float(value) => Some(value.to_owned()),
             _ => None
         }
     }
 
+    /// Returns the node's floating point content as an `f64`. Equivalent to
+    /// `toFloat`, named to mirror `toI64`.
+    pub fn toF64(&self) -> Option<f64> {
+        self.toFloat()
+    }
+
     pub fn toJson(&self) -> Option<Json> {
         match self {
             NodeContent::Json(value) => Some(value.clone()),
@@ -323,6 +518,43 @@ impl Node {
     }
 }
 
+/// Controls how `Json::fromStringWithOptions` handles an object with a
+/// repeated key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateKeyPolicy {
+    /// Fail the parse, as strict JSON validators do.
+    Error,
+    /// Keep the first occurrence's value, ignore later ones.
+    First,
+    /// Keep the last occurrence's value, matching most JSON libraries.
+    Last
+}
+
+/// Options controlling `Json::fromStringWithOptions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub duplicateKeys: DuplicateKeyPolicy
+}
+
+impl ParseOptions {
+    pub fn new(duplicateKeys: DuplicateKeyPolicy) -> ParseOptions {
+        ParseOptions { duplicateKeys }
+    }
+}
+
+impl Default for ParseOptions {
+    /// Defaults to `DuplicateKeyPolicy::First`, matching `Json::fromString`.
+    ///
+    /// This matches the de facto behavior of releases before `ParseOptions`
+    /// existed: duplicate keys were stored as separate nodes and `get()`
+    /// returned on the first match it found, so the first occurrence always
+    /// won. Pass `DuplicateKeyPolicy::Last` explicitly if you want the
+    /// "last write wins" behavior most other JSON libraries default to.
+    fn default() -> ParseOptions {
+        ParseOptions { duplicateKeys: DuplicateKeyPolicy::First }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Json {
     nodes: Vec<Node>,
@@ -345,7 +577,17 @@ impl Json {
         }
     }
 
+    /// Parses `text`, keeping the first value of any repeated key
+    /// (`DuplicateKeyPolicy::First`), matching the de facto behavior of
+    /// releases before `ParseOptions` existed. Use `fromStringWithOptions`
+    /// to pick a different duplicate-key policy.
     pub fn fromString<T: ToString>(text: T) -> Result<Json, String> {
+        Json::fromStringWithOptions(text, ParseOptions::default())
+    }
+
+    /// Parses `text` with explicit control over duplicate-key handling via
+    /// `options`. In all cases, `nodes` preserves source order.
+    pub fn fromStringWithOptions<T: ToString>(text: T, options: ParseOptions) -> Result<Json, String> {
         let mut parser = Parser::new(text.to_string());
         let error = parser.parse();
 
@@ -361,7 +603,7 @@ impl Json {
 
         let index = 1_usize;
 
-        let (_, json, error) = Self::json(&tokens, index);
+        let (_, json, error) = Self::json(&tokens, index, options.duplicateKeys);
         if error {
             return Err(String::from("Json format error"));
         }
@@ -369,15 +611,16 @@ impl Json {
         return Ok(json.unwrap())
     }
 
-    fn json(tokens: &Vec<Token>, startIndex: usize) -> (usize, Option<Json>, bool) {
+    fn json(tokens: &Vec<Token>, startIndex: usize, duplicateKeys: DuplicateKeyPolicy) -> (usize, Option<Json>, bool) {
         let mut index = startIndex;
         let mut nodes = Vec::<Node>::new();
         let mut labels = HashSet::<String>::new();
+        let mut positions = HashMap::<String, usize>::new();
 
         while index < tokens.len() {
             match tokens.get(index).unwrap() {
                 Token::String(_) => {
-                    let (newIndex, node, error) = Self::node(&tokens, index);
+                    let (newIndex, node, error) = Self::node(&tokens, index, duplicateKeys);
 
                     if error {
                         return (index, None, true)
@@ -393,8 +636,22 @@ impl Json {
 
                     match node {
                         Some(node) => {
-                            labels.insert(node.label.clone());
-                            nodes.push(node);
+                            match positions.get(&node.label) {
+                                Some(&existingIndex) => {
+                                    match duplicateKeys {
+                                        DuplicateKeyPolicy::Error => return (index, None, true),
+                                        DuplicateKeyPolicy::First => {},
+                                        DuplicateKeyPolicy::Last => {
+                                            nodes[existingIndex].content = node.content;
+                                        }
+                                    }
+                                },
+                                None => {
+                                    positions.insert(node.label.clone(), nodes.len());
+                                    labels.insert(node.label.clone());
+                                    nodes.push(node);
+                                }
+                            }
                         },
                         None => {}
                     }
@@ -408,7 +665,7 @@ impl Json {
         (index, Some(Json{nodes: nodes, labels}), false)
     }
 
-    fn list(tokens: &Vec<Token>, startIndex: usize) -> (usize, Option<NodeContent>, bool) {
+    fn list(tokens: &Vec<Token>, startIndex: usize, duplicateKeys: DuplicateKeyPolicy) -> (usize, Option<NodeContent>, bool) {
         let mut index = startIndex;
         let mut content = Vec::<NodeContent>::new();
 
@@ -440,7 +697,7 @@ impl Json {
                 },
 
                 Token::OpenBrace => {
-                    let (newIndex, json, error) = Self::json(tokens, index + 1);
+                    let (newIndex, json, error) = Self::json(tokens, index + 1, duplicateKeys);
 
                     if error {
                         return (index, None, true)
@@ -451,7 +708,7 @@ impl Json {
                 },
 
                 Token::OpenBracket => {
-                    let (newIndex, list, error) = Self::list(tokens, index);
+                    let (newIndex, list, error) = Self::list(tokens, index, duplicateKeys);
 
                     if error {
                         return (index, None, true)
@@ -479,7 +736,7 @@ impl Json {
         (index, Some(NodeContent::List(content)), false)
     }
 
-    fn node(tokens: &Vec<Token>, startIndex: usize) -> (usize, Option<Node>, bool) {
+    fn node(tokens: &Vec<Token>, startIndex: usize, duplicateKeys: DuplicateKeyPolicy) -> (usize, Option<Node>, bool) {
         let mut index = startIndex;
         let label = tokens.get(index).unwrap().toString();
 
@@ -518,7 +775,7 @@ impl Json {
 
             Token::OpenBrace => {
                 index += 1;
-                let (newIndex, nodeContent, error) = Self::json(tokens, index);
+                let (newIndex, nodeContent, error) = Self::json(tokens, index, duplicateKeys);
                 if error {
                     return (index, None, true)
                 }
@@ -528,7 +785,7 @@ impl Json {
 
             Token::OpenBracket => {
                 index += 1;
-                let (newIndex, list, error) = Self::list(tokens, index);
+                let (newIndex, list, error) = Self::list(tokens, index, duplicateKeys);
 
                 if error {
                     return (index, None, true);
@@ -607,15 +864,90 @@ impl Json {
     pub fn renderContent(object: &NodeContent) -> String {
         match object {
             NodeContent::Bool(bool) => if *bool { String::from("true") } else { String::from("false") },
-            NodeContent::Float(float) => format!("{}", float),
+            NodeContent::Float(float) => Self::renderFloat(*float),
             NodeContent::Int(int) => format!("{}", int),
             NodeContent::Null => String::from("null"),
-            NodeContent::String(string) => format!("\"{}\"", string.replace("\\", "\\\\").replace("\"", "\\\"")),
+            NodeContent::String(string) => format!("\"{}\"", Self::escapeString(string)),
             NodeContent::List(list) => Self::renderList(&list),
             NodeContent::Json(json) => Self::renderJson(&json),
         }
     }
 
+    /// Renders a float so it keeps a decimal point even when it has no
+    /// fractional digits, so it round-trips back into a `Float` (rather
+    /// than an `Int`) when the output is re-parsed.
+    fn renderFloat(float: f64) -> String {
+        let rendered = format!("{}", float);
+        if rendered.contains(['.', 'e', 'E']) || rendered.contains("inf") || rendered.contains("NaN") {
+            rendered
+        } else {
+            format!("{}.0", rendered)
+        }
+    }
+
+    /// Escapes a raw string's content for JSON output: backslashes, quotes
+    /// and the named control character escapes (`\b \f \n \r \t`), with any
+    /// other control character falling back to `\u00XX`.
+    fn escapeString(string: &str) -> String {
+        let mut escaped = String::with_capacity(string.len());
+
+        for c in string.chars() {
+            match c {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\u{8}' => escaped.push_str("\\b"),
+                '\u{c}' => escaped.push_str("\\f"),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+                c => escaped.push(c)
+            }
+        }
+
+        escaped
+    }
+
+    fn renderJsonIndented(json: &Json, indent: usize, depth: usize) -> String {
+        if json.nodes.is_empty() {
+            return String::from("{}");
+        }
+
+        let pad = " ".repeat(indent * (depth + 1));
+        let closePad = " ".repeat(indent * depth);
+
+        let mut lines = Vec::<String>::new();
+        for node in &json.nodes {
+            lines.push(format!("{}\"{}\": {}", pad, node.label, Self::renderContentIndented(&node.content, indent, depth + 1)));
+        }
+
+        format!("{{\n{}\n{}}}", lines.join(",\n"), closePad)
+    }
+
+    fn renderListIndented(list: &Vec<NodeContent>, indent: usize, depth: usize) -> String {
+        if list.is_empty() {
+            return String::from("[]");
+        }
+
+        let pad = " ".repeat(indent * (depth + 1));
+        let closePad = " ".repeat(indent * depth);
+
+        let mut lines = Vec::<String>::new();
+        for item in list {
+            lines.push(format!("{}{}", pad, Self::renderContentIndented(item, indent, depth + 1)));
+        }
+
+        format!("[\n{}\n{}]", lines.join(",\n"), closePad)
+    }
+
+    fn renderContentIndented(object: &NodeContent, indent: usize, depth: usize) -> String {
+        match object {
+            NodeContent::List(list) => Self::renderListIndented(list, indent, depth),
+            NodeContent::Json(json) => Self::renderJsonIndented(json, indent, depth),
+            other => Self::renderContent(other)
+        }
+    }
+
     /// Exports the Json struct into a Json file and writes it into `fileName`
     pub fn writeToFile<T: ToString>(&self, fileName: T) -> bool {
         let content = Json::renderJson(self);
@@ -626,13 +958,33 @@ impl Json {
         }
     }
 
+    /// Exports the Json struct into a Json file, pretty-printed with
+    /// `indent` spaces per nesting level, and writes it into `fileName`
+    pub fn writeToFilePretty<T: ToString>(&self, fileName: T, indent: usize) -> bool {
+        let content = self.toStringPretty(indent);
+
+        return match fs::write(path::Path::new(&fileName.to_string()), content) {
+            Err(_) => false,
+            Ok(_) => true
+        }
+    }
+
     /// Exports the Json struct into a json-formatted string
     pub fn toString(&self) -> String {
         return Json::renderJson(self);
     }
 
+    /// Exports the Json struct into a human-readable json-formatted string,
+    /// with nested objects and arrays newline-separated and indented by
+    /// `indent` spaces per nesting level. Empty objects/arrays are still
+    /// rendered on a single line as `{}`/`[]`.
+    pub fn toStringPretty(&self, indent: usize) -> String {
+        return Json::renderJsonIndented(self, indent, 0);
+    }
+
     /// Adds a node to the Json struct
     pub fn addNode(&mut self, node: Node) {
+        self.labels.insert(node.label.clone());
         self.nodes.push(node);
     }
 
@@ -651,11 +1003,13 @@ impl Json {
 
     /// Removes a node basing on its label
     pub fn remove<T: ToString>(&mut self, label: T) -> bool {
+        let label = label.to_string();
         let mut index: usize = 0;
 
         for node in &self.nodes {
-            if node.label == label.to_string() {
+            if node.label == label {
                 self.nodes.remove(index);
+                self.labels.remove(&label);
 
                 return true;
             }
@@ -678,7 +1032,7 @@ impl Json {
 #[macro_export]
 macro_rules! json {
     ( $string:expr ) => {
-        Json::fromString($string)
+        $crate::Json::fromString($string)
     };
 }
 
@@ -694,4 +1048,148 @@ mod tests {
         println!("{:?}", j);
         return;
     }
+
+    #[test]
+    fn testNumbers() {
+        let j = Json::fromString(r#"{
+            "zero": -0,
+            "negative": -42,
+            "big": 9223372036854770000,
+            "exponent": 1e10,
+            "negativeExponent": 3.5E-2
+        }"#).unwrap();
+
+        assert_eq!(j.get("zero").unwrap().toI64(), Some(0));
+        assert_eq!(j.get("negative").unwrap().toI64(), Some(-42));
+        assert_eq!(j.get("big").unwrap().toI64(), Some(9223372036854770000));
+        assert_eq!(j.get("exponent").unwrap().toFloat(), Some(1e10));
+        assert_eq!(j.get("negativeExponent").unwrap().toFloat(), Some(3.5E-2));
+    }
+
+    #[test]
+    fn testIntegerOverflowFallsBackToFloat() {
+        let j = Json::fromString(r#"{"huge": 99999999999999999999999999}"#).unwrap();
+
+        assert_eq!(j.get("huge").unwrap().toFloat(), Some(99999999999999999999999999_f64));
+        assert_eq!(j.get("huge").unwrap().toI64(), None);
+    }
+
+    #[test]
+    fn testAddNodeAndRemoveUpdateLabels() {
+        let mut j = Json::new();
+        assert!(!j.has("greeting"));
+
+        j.addNode(Node::new("greeting", NodeContent::String(String::from("hi"))));
+        assert!(j.has("greeting"));
+
+        assert!(j.remove("greeting"));
+        assert!(!j.has("greeting"));
+    }
+
+    #[test]
+    fn testStringEscapes() {
+        let j = Json::fromString(r#"{
+            "newline": "a\nb",
+            "slash": "a\/b",
+            "emoji": "😀",
+            "unicode": "café"
+        }"#).unwrap();
+
+        assert_eq!(j.get("newline").unwrap().toString(), Some(String::from("a\nb")));
+        assert_eq!(j.get("slash").unwrap().toString(), Some(String::from("a/b")));
+        assert_eq!(j.get("emoji").unwrap().toString(), Some(String::from("\u{1F600}")));
+        assert_eq!(j.get("unicode").unwrap().toString(), Some(String::from("café")));
+    }
+
+    #[test]
+    fn testStringWithEmbeddedQuoteDecodesLiterally() {
+        let j = Json::fromString(r#"{"s": "a\"b"}"#).unwrap();
+        assert_eq!(j.get("s").unwrap().toString(), Some(String::from("a\"b")));
+    }
+
+    #[test]
+    fn testRenderRoundTripsEscapes() {
+        let mut j = Json::new();
+        j.addNode(Node::new("greeting", NodeContent::String(String::from("line one\nline two\ttabbed"))));
+
+        let rendered = j.toString();
+        let reparsed = Json::fromString(rendered).unwrap();
+
+        assert_eq!(reparsed.get("greeting").unwrap().toString(), Some(String::from("line one\nline two\ttabbed")));
+    }
+
+    #[test]
+    fn testRenderWholeNumberFloatKeepsDecimalPoint() {
+        let mut j = Json::new();
+        j.addNode(Node::new("price", NodeContent::Float(2.0)));
+
+        let rendered = j.toString();
+        assert!(rendered.contains("\"price\":2.0"));
+
+        let reparsed = Json::fromString(rendered).unwrap();
+        assert_eq!(reparsed.get("price").unwrap().toFloat(), Some(2.0));
+    }
+
+    #[test]
+    fn testMultibyteUtf8Input() {
+        let j = Json::fromString(r#"{"city": "Città", "greeting": "こんにちは"}"#).unwrap();
+
+        assert_eq!(j.get("city").unwrap().toString(), Some(String::from("Città")));
+        assert_eq!(j.get("greeting").unwrap().toString(), Some(String::from("こんにちは")));
+    }
+
+    #[test]
+    fn testPrettyPrint() {
+        let mut j = Json::new();
+        j.addNode(Node::new("name", NodeContent::String(String::from("rsjson"))));
+        j.addNode(Node::new("tags", NodeContent::List(vec![NodeContent::String(String::from("json"))])));
+        j.addNode(Node::new("empty", NodeContent::List(vec![])));
+
+        let expected = "{\n  \"name\": \"rsjson\",\n  \"tags\": [\n    \"json\"\n  ],\n  \"empty\": []\n}";
+        assert_eq!(j.toStringPretty(2), expected);
+
+        let reparsed = Json::fromString(j.toStringPretty(4)).unwrap();
+        assert_eq!(reparsed.getAllNodes(), j.getAllNodes());
+    }
+
+    #[test]
+    fn testPrettyPrintEmptyObject() {
+        let j = Json::new();
+        assert_eq!(j.toStringPretty(2), "{}");
+    }
+
+    #[test]
+    fn testDuplicateKeysDefaultsToFirst() {
+        let j = Json::fromString(r#"{"name": "first", "name": "second"}"#).unwrap();
+
+        assert_eq!(j.get("name").unwrap().toString(), Some(String::from("first")));
+        assert_eq!(j.getAllNodes().len(), 1);
+    }
+
+    #[test]
+    fn testDuplicateKeysFirst() {
+        let options = ParseOptions::new(DuplicateKeyPolicy::First);
+        let j = Json::fromStringWithOptions(r#"{"name": "first", "name": "second"}"#, options).unwrap();
+
+        assert_eq!(j.get("name").unwrap().toString(), Some(String::from("first")));
+        assert_eq!(j.getAllNodes().len(), 1);
+    }
+
+    #[test]
+    fn testDuplicateKeysError() {
+        let options = ParseOptions::new(DuplicateKeyPolicy::Error);
+        let result = Json::fromStringWithOptions(r#"{"name": "first", "name": "second"}"#, options);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn testDuplicateKeysPreserveOrder() {
+        let options = ParseOptions::new(DuplicateKeyPolicy::Last);
+        let j = Json::fromStringWithOptions(r#"{"a": 1, "b": 2, "a": 3}"#, options).unwrap();
+
+        let labels: Vec<String> = j.getAllNodes().iter().map(|node| node.getLabel()).collect();
+        assert_eq!(labels, vec![String::from("a"), String::from("b")]);
+        assert_eq!(j.get("a").unwrap().toI64(), Some(3));
+    }
 }
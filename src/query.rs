@@ -0,0 +1,864 @@
+//! JSONPath-style query engine for `Json`
+//!
+//! Supports a practical subset of JSONPath: the root selector `$`, child
+//! access (`.name` and `["name"]`), the wildcard `.*`, recursive descent
+//! `..name`, array indices `[n]` (negative indices count from the end),
+//! array slices `[start:end:step]` and filter predicates
+//! `[?(@.key OP value)]` with `OP` one of `== != < <= > >=`.
+
+use crate::{Json, NodeContent};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Dollar,
+    Dot,
+    DotDot,
+    Star,
+    LBracket,
+    RBracket,
+    Colon,
+    Question,
+    At,
+    Ident(String),
+    StringLit(String),
+    Number(i64),
+    Float(f64),
+    Op(String),
+}
+
+fn tokenize(path: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = path.chars().collect();
+    let len = chars.len();
+    let mut index = 0_usize;
+    let mut tokens = Vec::<Token>::new();
+
+    while index < len {
+        let current = chars[index];
+
+        if current == '$' {
+            tokens.push(Token::Dollar);
+            index += 1;
+
+        } else if current == '.' {
+            if index + 1 < len && chars[index + 1] == '.' {
+                tokens.push(Token::DotDot);
+                index += 2;
+            } else {
+                tokens.push(Token::Dot);
+                index += 1;
+            }
+
+        } else if current == '*' {
+            tokens.push(Token::Star);
+            index += 1;
+
+        } else if current == '[' {
+            tokens.push(Token::LBracket);
+            index += 1;
+
+        } else if current == ']' {
+            tokens.push(Token::RBracket);
+            index += 1;
+
+        } else if current == ':' {
+            tokens.push(Token::Colon);
+            index += 1;
+
+        } else if current == '?' {
+            tokens.push(Token::Question);
+            index += 1;
+
+        } else if current == '@' {
+            tokens.push(Token::At);
+            index += 1;
+
+        } else if current == '(' || current == ')' {
+            index += 1;
+
+        } else if current == '\'' || current == '"' {
+            let quote = current;
+            index += 1;
+            let mut value = String::new();
+
+            while index < len && chars[index] != quote {
+                value.push(chars[index]);
+                index += 1;
+            }
+
+            if index == len {
+                return Err(String::from("unterminated string in path expression"));
+            }
+            index += 1;
+            tokens.push(Token::StringLit(value));
+
+        } else if current == '=' || current == '!' || current == '<' || current == '>' {
+            let mut op = String::from(current);
+            index += 1;
+
+            if index < len && chars[index] == '=' {
+                op.push('=');
+                index += 1;
+            }
+            tokens.push(Token::Op(op));
+
+        } else if current == '-' || current.is_ascii_digit() {
+            let mut value = String::from(current);
+            index += 1;
+
+            while index < len && chars[index].is_ascii_digit() {
+                value.push(chars[index]);
+                index += 1;
+            }
+
+            let mut isFloat = false;
+            if index + 1 < len && chars[index] == '.' && chars[index + 1].is_ascii_digit() {
+                isFloat = true;
+                value.push(chars[index]);
+                index += 1;
+
+                while index < len && chars[index].is_ascii_digit() {
+                    value.push(chars[index]);
+                    index += 1;
+                }
+            }
+
+            if isFloat {
+                match value.parse::<f64>() {
+                    Ok(number) => tokens.push(Token::Float(number)),
+                    Err(_) => return Err(format!("invalid number '{}' in path expression", value))
+                }
+            } else {
+                match value.parse::<i64>() {
+                    Ok(number) => tokens.push(Token::Number(number)),
+                    Err(_) => return Err(format!("invalid number '{}' in path expression", value))
+                }
+            }
+
+        } else if current.is_alphanumeric() || current == '_' {
+            let mut value = String::from(current);
+            index += 1;
+
+            while index < len && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                value.push(chars[index]);
+                index += 1;
+            }
+            tokens.push(Token::Ident(value));
+
+        } else if current.is_whitespace() {
+            index += 1;
+
+        } else {
+            return Err(format!("unexpected character '{}' in path expression", current));
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FilterOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FilterValue {
+    String(String),
+    Number(f64),
+    Bool(bool),
+    Null
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Filter {
+    key: String,
+    op: FilterOp,
+    value: FilterValue
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Child(String),
+    Wildcard,
+    RecursiveDescent(String),
+    Index(i64),
+    Slice(Option<i64>, Option<i64>, Option<i64>),
+    Filter(Filter)
+}
+
+fn parseSteps(tokens: &[Token]) -> Result<Vec<Step>, String> {
+    let mut steps = Vec::<Step>::new();
+    let mut index = 0_usize;
+
+    if tokens.get(index) != Some(&Token::Dollar) {
+        return Err(String::from("path expression must start with '$'"));
+    }
+    index += 1;
+
+    while index < tokens.len() {
+        match tokens.get(index).unwrap() {
+            Token::DotDot => {
+                index += 1;
+                match tokens.get(index) {
+                    Some(Token::Ident(name)) => {
+                        steps.push(Step::RecursiveDescent(name.clone()));
+                        index += 1;
+                    },
+                    _ => return Err(String::from("expected a name after '..'"))
+                }
+            },
+
+            Token::Dot => {
+                index += 1;
+                match tokens.get(index) {
+                    Some(Token::Ident(name)) => {
+                        steps.push(Step::Child(name.clone()));
+                        index += 1;
+                    },
+                    Some(Token::Star) => {
+                        steps.push(Step::Wildcard);
+                        index += 1;
+                    },
+                    _ => return Err(String::from("expected a name or '*' after '.'"))
+                }
+            },
+
+            Token::LBracket => {
+                index += 1;
+                let (step, newIndex) = parseBracket(tokens, index)?;
+                steps.push(step);
+                index = newIndex;
+            },
+
+            _ => return Err(format!("unexpected token at position {}", index))
+        }
+    }
+
+    Ok(steps)
+}
+
+fn parseBracket(tokens: &[Token], startIndex: usize) -> Result<(Step, usize), String> {
+    let mut index = startIndex;
+
+    match tokens.get(index) {
+        Some(Token::StringLit(name)) => {
+            index += 1;
+            expect(tokens, index, &Token::RBracket)?;
+            return Ok((Step::Child(name.clone()), index + 1));
+        },
+
+        Some(Token::Star) => {
+            index += 1;
+            expect(tokens, index, &Token::RBracket)?;
+            return Ok((Step::Wildcard, index + 1));
+        },
+
+        Some(Token::Question) => {
+            index += 1;
+            let (filter, newIndex) = parseFilter(tokens, index)?;
+            expect(tokens, newIndex, &Token::RBracket)?;
+            return Ok((Step::Filter(filter), newIndex + 1));
+        },
+
+        _ => {}
+    }
+
+    let start = readSignedNumber(tokens, &mut index);
+
+    if tokens.get(index) == Some(&Token::Colon) {
+        index += 1;
+        let end = readSignedNumber(tokens, &mut index);
+
+        let mut step = None;
+        if tokens.get(index) == Some(&Token::Colon) {
+            index += 1;
+            step = readSignedNumber(tokens, &mut index);
+        }
+
+        expect(tokens, index, &Token::RBracket)?;
+        return Ok((Step::Slice(start, end, step), index + 1));
+    }
+
+    match start {
+        Some(number) => {
+            expect(tokens, index, &Token::RBracket)?;
+            Ok((Step::Index(number), index + 1))
+        },
+        None => Err(String::from("expected an index, slice or filter inside '[...]'"))
+    }
+}
+
+fn readSignedNumber(tokens: &[Token], index: &mut usize) -> Option<i64> {
+    match tokens.get(*index) {
+        Some(Token::Number(number)) => {
+            *index += 1;
+            Some(*number)
+        },
+        _ => None
+    }
+}
+
+fn expect(tokens: &[Token], index: usize, expected: &Token) -> Result<(), String> {
+    if tokens.get(index) == Some(expected) {
+        Ok(())
+    } else {
+        Err(format!("expected {:?} at position {}", expected, index))
+    }
+}
+
+fn parseFilter(tokens: &[Token], startIndex: usize) -> Result<(Filter, usize), String> {
+    let mut index = startIndex;
+
+    expect(tokens, index, &Token::At)?;
+    index += 1;
+    expect(tokens, index, &Token::Dot)?;
+    index += 1;
+
+    let key = match tokens.get(index) {
+        Some(Token::Ident(name)) => name.clone(),
+        _ => return Err(String::from("expected a field name after '@.' in filter"))
+    };
+    index += 1;
+
+    let op = match tokens.get(index) {
+        Some(Token::Op(op)) => match op.as_str() {
+            "==" => FilterOp::Eq,
+            "!=" => FilterOp::Ne,
+            "<" => FilterOp::Lt,
+            "<=" => FilterOp::Le,
+            ">" => FilterOp::Gt,
+            ">=" => FilterOp::Ge,
+            other => return Err(format!("unsupported filter operator '{}'", other))
+        },
+        _ => return Err(String::from("expected a comparison operator in filter"))
+    };
+    index += 1;
+
+    let value = match tokens.get(index) {
+        Some(Token::StringLit(string)) => FilterValue::String(string.clone()),
+        Some(Token::Number(number)) => FilterValue::Number(*number as f64),
+        Some(Token::Float(number)) => FilterValue::Number(*number),
+        Some(Token::Ident(ident)) if ident == "true" => FilterValue::Bool(true),
+        Some(Token::Ident(ident)) if ident == "false" => FilterValue::Bool(false),
+        Some(Token::Ident(ident)) if ident == "null" => FilterValue::Null,
+        _ => return Err(String::from("expected a value in filter"))
+    };
+    index += 1;
+
+    Ok((Filter{key, op, value}, index))
+}
+
+enum Selected<'a> {
+    Root(&'a Json),
+    Node(&'a NodeContent)
+}
+
+fn normalizeIndex(index: i64, length: usize) -> Option<usize> {
+    let resolved = if index < 0 { index + length as i64 } else { index };
+
+    if resolved < 0 || resolved >= length as i64 {
+        None
+    } else {
+        Some(resolved as usize)
+    }
+}
+
+fn applyChild<'a>(selected: &Selected<'a>, name: &str, out: &mut Vec<Selected<'a>>) {
+    let json = match selected {
+        Selected::Root(json) => Some(*json),
+        Selected::Node(NodeContent::Json(json)) => Some(json),
+        _ => None
+    };
+
+    if let Some(json) = json {
+        if let Some(content) = json.get(name) {
+            out.push(Selected::Node(content));
+        }
+    }
+}
+
+fn applyWildcard<'a>(selected: &Selected<'a>, out: &mut Vec<Selected<'a>>) {
+    match selected {
+        Selected::Root(json) => {
+            for node in &json.nodes {
+                out.push(Selected::Node(&node.content));
+            }
+        },
+        Selected::Node(NodeContent::Json(json)) => {
+            for node in &json.nodes {
+                out.push(Selected::Node(&node.content));
+            }
+        },
+        Selected::Node(NodeContent::List(list)) => {
+            for item in list {
+                out.push(Selected::Node(item));
+            }
+        },
+        _ => {}
+    }
+}
+
+fn recursiveDescent<'a>(json: &'a Json, name: &str, out: &mut Vec<Selected<'a>>) {
+    for node in &json.nodes {
+        if node.label == name {
+            out.push(Selected::Node(&node.content));
+        }
+        collectNested(&node.content, name, out);
+    }
+}
+
+fn collectNested<'a>(content: &'a NodeContent, name: &str, out: &mut Vec<Selected<'a>>) {
+    match content {
+        NodeContent::Json(json) => recursiveDescent(json, name, out),
+        NodeContent::List(list) => {
+            for item in list {
+                collectNested(item, name, out);
+            }
+        },
+        _ => {}
+    }
+}
+
+fn applyIndex<'a>(selected: &Selected<'a>, index: i64, out: &mut Vec<Selected<'a>>) {
+    if let Selected::Node(NodeContent::List(list)) = selected {
+        if let Some(resolved) = normalizeIndex(index, list.len()) {
+            out.push(Selected::Node(&list[resolved]));
+        }
+    }
+}
+
+fn applySlice<'a>(selected: &Selected<'a>, start: Option<i64>, end: Option<i64>, step: Option<i64>, out: &mut Vec<Selected<'a>>) {
+    if let Selected::Node(NodeContent::List(list)) = selected {
+        for index in sliceIndices(start, end, step, list.len()) {
+            out.push(Selected::Node(&list[index]));
+        }
+    }
+}
+
+fn normalizeBound(value: i64, length: usize) -> usize {
+    let resolved = if value < 0 { value + length as i64 } else { value };
+    resolved.clamp(0, length as i64) as usize
+}
+
+/// Resolves a `[start:end:step]` slice into the ordered list of element
+/// indices it selects, so the mutable and read-only walks can share it.
+fn sliceIndices(start: Option<i64>, end: Option<i64>, step: Option<i64>, length: usize) -> Vec<usize> {
+    let mut indices = Vec::new();
+
+    if length == 0 {
+        return indices;
+    }
+
+    let step = step.unwrap_or(1);
+    if step == 0 {
+        return indices;
+    }
+
+    let startIndex = match start {
+        Some(value) => normalizeBound(value, length),
+        None => if step > 0 { 0 } else { length - 1 }
+    };
+
+    if step > 0 {
+        let endIndex = match end {
+            Some(value) => normalizeBound(value, length),
+            None => length
+        };
+
+        let mut i = startIndex;
+        while i < endIndex && i < length {
+            indices.push(i);
+            i += step as usize;
+        }
+    } else {
+        // An omitted `end` on a negative step must include index `0`, so the
+        // stop bound needs a value below any valid index rather than `0`
+        // itself, which the exclusive `i > stop` test would otherwise skip.
+        let stop = match end {
+            Some(value) => normalizeBound(value, length) as i64,
+            None => -1
+        };
+
+        let mut i = (startIndex as i64).min(length as i64 - 1);
+        while i > stop && i >= 0 {
+            indices.push(i as usize);
+            i += step;
+        }
+    }
+
+    indices
+}
+
+fn compareNumbers(left: f64, op: &FilterOp, right: f64) -> bool {
+    match op {
+        FilterOp::Eq => left == right,
+        FilterOp::Ne => left != right,
+        FilterOp::Lt => left < right,
+        FilterOp::Le => left <= right,
+        FilterOp::Gt => left > right,
+        FilterOp::Ge => left >= right
+    }
+}
+
+fn compareStrings(left: &str, op: &FilterOp, right: &str) -> bool {
+    match op {
+        FilterOp::Eq => left == right,
+        FilterOp::Ne => left != right,
+        FilterOp::Lt => left < right,
+        FilterOp::Le => left <= right,
+        FilterOp::Gt => left > right,
+        FilterOp::Ge => left >= right
+    }
+}
+
+fn matchesFilter(content: &NodeContent, filter: &Filter) -> bool {
+    let json = match content {
+        NodeContent::Json(json) => json,
+        _ => return false
+    };
+
+    let field = match json.get(&filter.key) {
+        Some(field) => field,
+        None => return false
+    };
+
+    match (field, &filter.value) {
+        (NodeContent::Int(value), FilterValue::Number(expected)) => compareNumbers(*value as f64, &filter.op, *expected),
+        (NodeContent::Float(value), FilterValue::Number(expected)) => compareNumbers(*value, &filter.op, *expected),
+        (NodeContent::String(value), FilterValue::String(expected)) => compareStrings(value, &filter.op, expected),
+        (NodeContent::Bool(value), FilterValue::Bool(expected)) => match filter.op {
+            FilterOp::Eq => value == expected,
+            FilterOp::Ne => value != expected,
+            _ => false
+        },
+        (NodeContent::Null, FilterValue::Null) => matches!(filter.op, FilterOp::Eq),
+        _ => false
+    }
+}
+
+fn applyFilter<'a>(selected: &Selected<'a>, filter: &Filter, out: &mut Vec<Selected<'a>>) {
+    if let Selected::Node(NodeContent::List(list)) = selected {
+        for item in list {
+            if matchesFilter(item, filter) {
+                out.push(Selected::Node(item));
+            }
+        }
+    }
+}
+
+fn evaluate<'a>(json: &'a Json, steps: &Vec<Step>) -> Vec<&'a NodeContent> {
+    let mut current = vec![Selected::Root(json)];
+
+    for step in steps {
+        let mut next = Vec::<Selected<'a>>::new();
+
+        for selected in &current {
+            match step {
+                Step::Child(name) => applyChild(selected, name, &mut next),
+                Step::Wildcard => applyWildcard(selected, &mut next),
+                Step::RecursiveDescent(name) => {
+                    match selected {
+                        Selected::Root(json) => recursiveDescent(json, name, &mut next),
+                        Selected::Node(NodeContent::Json(json)) => recursiveDescent(json, name, &mut next),
+                        Selected::Node(NodeContent::List(list)) => {
+                            for item in list {
+                                collectNested(item, name, &mut next);
+                            }
+                        },
+                        _ => {}
+                    }
+                },
+                Step::Index(index) => applyIndex(selected, *index, &mut next),
+                Step::Slice(start, end, step) => applySlice(selected, *start, *end, *step, &mut next),
+                Step::Filter(filter) => applyFilter(selected, filter, &mut next)
+            }
+        }
+
+        current = next;
+    }
+
+    current.into_iter().filter_map(|selected| match selected {
+        Selected::Node(content) => Some(content),
+        Selected::Root(_) => None
+    }).collect()
+}
+
+enum SelectedMut<'a> {
+    Root(&'a mut Json),
+    Node(&'a mut NodeContent)
+}
+
+fn applyChildMut<'a>(selected: SelectedMut<'a>, name: &str, out: &mut Vec<SelectedMut<'a>>) {
+    let json = match selected {
+        SelectedMut::Root(json) => Some(json),
+        SelectedMut::Node(NodeContent::Json(json)) => Some(json),
+        _ => None
+    };
+
+    if let Some(json) = json {
+        if let Some(node) = json.nodes.iter_mut().find(|node| node.label == name) {
+            out.push(SelectedMut::Node(&mut node.content));
+        }
+    }
+}
+
+fn applyWildcardMut<'a>(selected: SelectedMut<'a>, out: &mut Vec<SelectedMut<'a>>) {
+    match selected {
+        SelectedMut::Root(json) => {
+            for node in &mut json.nodes {
+                out.push(SelectedMut::Node(&mut node.content));
+            }
+        },
+        SelectedMut::Node(NodeContent::Json(json)) => {
+            for node in &mut json.nodes {
+                out.push(SelectedMut::Node(&mut node.content));
+            }
+        },
+        SelectedMut::Node(NodeContent::List(list)) => {
+            for item in list {
+                out.push(SelectedMut::Node(item));
+            }
+        },
+        _ => {}
+    }
+}
+
+/// Mutable recursive descent. Unlike the read-only walk, a node whose label
+/// matches is not also searched for nested matches inside it: returning a
+/// `&mut` to the whole matched value and a second `&mut` into its interior
+/// would alias the same memory, which Rust can't allow. Non-matching nodes
+/// are still searched all the way down.
+fn recursiveDescentMut<'a>(json: &'a mut Json, name: &str, out: &mut Vec<SelectedMut<'a>>) {
+    for node in &mut json.nodes {
+        if node.label == name {
+            out.push(SelectedMut::Node(&mut node.content));
+        } else {
+            collectNestedMut(&mut node.content, name, out);
+        }
+    }
+}
+
+fn collectNestedMut<'a>(content: &'a mut NodeContent, name: &str, out: &mut Vec<SelectedMut<'a>>) {
+    match content {
+        NodeContent::Json(json) => recursiveDescentMut(json, name, out),
+        NodeContent::List(list) => {
+            for item in list {
+                collectNestedMut(item, name, out);
+            }
+        },
+        _ => {}
+    }
+}
+
+fn applyIndexMut<'a>(selected: SelectedMut<'a>, index: i64, out: &mut Vec<SelectedMut<'a>>) {
+    if let SelectedMut::Node(NodeContent::List(list)) = selected {
+        if let Some(resolved) = normalizeIndex(index, list.len()) {
+            out.push(SelectedMut::Node(&mut list[resolved]));
+        }
+    }
+}
+
+fn applySliceMut<'a>(selected: SelectedMut<'a>, start: Option<i64>, end: Option<i64>, step: Option<i64>, out: &mut Vec<SelectedMut<'a>>) {
+    if let SelectedMut::Node(NodeContent::List(list)) = selected {
+        let indices = sliceIndices(start, end, step, list.len());
+        let mut slots: Vec<Option<&'a mut NodeContent>> = list.iter_mut().map(Some).collect();
+
+        for index in indices {
+            if let Some(item) = slots[index].take() {
+                out.push(SelectedMut::Node(item));
+            }
+        }
+    }
+}
+
+fn applyFilterMut<'a>(selected: SelectedMut<'a>, filter: &Filter, out: &mut Vec<SelectedMut<'a>>) {
+    if let SelectedMut::Node(NodeContent::List(list)) = selected {
+        for item in list {
+            if matchesFilter(item, filter) {
+                out.push(SelectedMut::Node(item));
+            }
+        }
+    }
+}
+
+fn evaluateMut<'a>(json: &'a mut Json, steps: &Vec<Step>) -> Vec<&'a mut NodeContent> {
+    let mut current = vec![SelectedMut::Root(json)];
+
+    for step in steps {
+        let mut next = Vec::<SelectedMut<'a>>::new();
+
+        for selected in current.into_iter() {
+            match step {
+                Step::Child(name) => applyChildMut(selected, name, &mut next),
+                Step::Wildcard => applyWildcardMut(selected, &mut next),
+                Step::RecursiveDescent(name) => {
+                    match selected {
+                        SelectedMut::Root(json) => recursiveDescentMut(json, name, &mut next),
+                        SelectedMut::Node(NodeContent::Json(json)) => recursiveDescentMut(json, name, &mut next),
+                        SelectedMut::Node(NodeContent::List(list)) => {
+                            for item in list {
+                                collectNestedMut(item, name, &mut next);
+                            }
+                        },
+                        _ => {}
+                    }
+                },
+                Step::Index(index) => applyIndexMut(selected, *index, &mut next),
+                Step::Slice(start, end, step) => applySliceMut(selected, *start, *end, *step, &mut next),
+                Step::Filter(filter) => applyFilterMut(selected, filter, &mut next)
+            }
+        }
+
+        current = next;
+    }
+
+    current.into_iter().filter_map(|selected| match selected {
+        SelectedMut::Node(content) => Some(content),
+        SelectedMut::Root(_) => None
+    }).collect()
+}
+
+impl Json {
+    /// Evaluates a JSONPath-style expression against the node tree and returns
+    /// references to every matching value, in document order, except that a
+    /// slice with a negative step yields its elements from the end backwards.
+    ///
+    /// Supports the root selector `$`, child access (`.name` and `["name"]`),
+    /// the wildcard `.*`/`[*]`, recursive descent `..name`, array indices
+    /// `[n]` (negative indices count from the end), array slices
+    /// `[start:end:step]` and filter predicates `[?(@.key OP value)]` with
+    /// `OP` one of `== != < <= > >=`.
+    ///
+    /// Returns an empty vector if the expression is malformed or matches
+    /// nothing.
+    pub fn query(&self, path: &str) -> Vec<&NodeContent> {
+        let tokens = match tokenize(path) {
+            Ok(tokens) => tokens,
+            Err(_) => return Vec::new()
+        };
+
+        let steps = match parseSteps(&tokens) {
+            Ok(steps) => steps,
+            Err(_) => return Vec::new()
+        };
+
+        evaluate(self, &steps)
+    }
+
+    /// Like [`Json::query`], but returns mutable references so matched
+    /// values can be edited in place.
+    ///
+    /// Recursive descent (`..name`) has one difference from the read-only
+    /// `query`: a node whose label matches is not also searched for further
+    /// matches nested inside it, since that would require two overlapping
+    /// `&mut` references into the same value. Every other step behaves the
+    /// same as `query`.
+    ///
+    /// Returns an empty vector if the expression is malformed or matches
+    /// nothing.
+    pub fn query_mut(&mut self, path: &str) -> Vec<&mut NodeContent> {
+        let tokens = match tokenize(path) {
+            Ok(tokens) => tokens,
+            Err(_) => return Vec::new()
+        };
+
+        let steps = match parseSteps(&tokens) {
+            Ok(steps) => steps,
+            Err(_) => return Vec::new()
+        };
+
+        evaluateMut(self, &steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load() -> Json {
+        let content = std::fs::read_to_string("./map.json").unwrap();
+        Json::fromString(content).unwrap()
+    }
+
+    #[test]
+    fn testChildAndIndex() {
+        let json = load();
+        let result = json.query("$.store.departments[0].name");
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].toString(), Some(String::from("produce")));
+    }
+
+    #[test]
+    fn testWildcard() {
+        let json = load();
+        let result = json.query("$.staff.*");
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn testRecursiveDescent() {
+        let json = load();
+        let result = json.query("$..name");
+        assert_eq!(result.len(), 9);
+    }
+
+    #[test]
+    fn testSlice() {
+        let json = load();
+        let result = json.query("$.store.departments[0:1]");
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn testSliceNegativeStepOpenEnded() {
+        let json = load();
+        let result = json.query("$.store.departments[0].items[0].tags[::-1]");
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].toString(), Some(String::from("local")));
+        assert_eq!(result[1].toString(), Some(String::from("fresh")));
+    }
+
+    #[test]
+    fn testFilter() {
+        let json = load();
+        let result = json.query("$.staff[?(@.role == \"cashier\")]");
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn testRecursiveDescentIntoList() {
+        let json = load();
+        let result = json.query("$.staff..name");
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].toString(), Some(String::from("Alice")));
+        assert_eq!(result[1].toString(), Some(String::from("Bob")));
+    }
+
+    #[test]
+    fn testFilterWithFloatValue() {
+        let json = load();
+        let result = json.query("$.store.departments[0].items[?(@.price < 1.0)]");
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].toJson().unwrap().get("name").unwrap().toString(), Some(String::from("banana")));
+    }
+
+    #[test]
+    fn testQueryMut() {
+        let mut json = load();
+
+        for content in json.query_mut("$.staff.*.role") {
+            *content = NodeContent::String(String::from("employee"));
+        }
+
+        let result = json.query("$.staff.*.role");
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|content| content.toString() == Some(String::from("employee"))));
+    }
+}
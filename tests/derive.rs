@@ -0,0 +1,56 @@
+#![cfg(feature = "derive")]
+#![allow(non_snake_case)]
+
+use rsjson::{FromJson, Json, NodeContent, ToJson};
+
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+struct Address {
+    city: String,
+    #[rsjson(rename = "zip_code")]
+    zip: String
+}
+
+#[derive(ToJson, FromJson, Debug, PartialEq)]
+struct Person {
+    name: String,
+    age: u32,
+    address: Address,
+    #[rsjson(skip)]
+    cachedGreeting: String
+}
+
+#[test]
+fn testDeriveRoundTrip() {
+    let person = Person {
+        name: String::from("Ada"),
+        age: 30,
+        address: Address { city: String::from("Turin"), zip: String::from("10100") },
+        cachedGreeting: String::new()
+    };
+
+    let content = person.to_json();
+    let json = content.toJson().unwrap();
+
+    assert_eq!(json.get("name").unwrap().toString(), Some(String::from("Ada")));
+    assert!(!json.has("cachedGreeting"));
+
+    let address = json.get("address").unwrap().toJson().unwrap();
+    assert_eq!(address.get("zip_code").unwrap().toString(), Some(String::from("10100")));
+
+    let rebuilt = Person::from_json(&content).unwrap();
+    assert_eq!(rebuilt, Person {
+        name: String::from("Ada"),
+        age: 30,
+        address: Address { city: String::from("Turin"), zip: String::from("10100") },
+        cachedGreeting: String::new()
+    });
+}
+
+#[test]
+fn testDeriveMissingFieldError() {
+    let json = Json::fromString(r#"{"name": "Ada"}"#).unwrap();
+    let result = Person::from_json(&NodeContent::Json(json));
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("age"));
+}